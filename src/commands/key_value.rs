@@ -1,14 +1,17 @@
 use crate::commands::links_output::{
-    print_json, print_table, prompt_delete_resource, ListFormat, ResourceGroupBy, ResourceLinks,
-    ResourceType,
+    print_table, prompt_delete_resource, ListFormat, ResourceGroupBy, ResourceLinks, ResourceType,
 };
 use crate::commands::links_target::ResourceTarget;
 use crate::commands::{create_cloud_client, disallow_empty, CommonArgs};
 use anyhow::{bail, Context, Result};
 use clap::{Parser, ValueEnum};
 use cloud::CloudClientInterface;
-use cloud_openapi::models::KeyValueStoreItem;
+use cloud_openapi::models::{KeyValueStoreItem, KeyValueStoreUsage};
+use dialoguer::Confirm;
+use serde::Deserialize;
 use spin_common::arg_parser::parse_kv;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(about = "Manage Fermyon Cloud key value stores")]
@@ -19,8 +22,20 @@ pub enum KeyValueCommand {
     Delete(DeleteCommand),
     /// List key value stores
     List(ListCommand),
+    /// List the keys in a key value store
+    Keys(KeysCommand),
     /// Set a key value pair in a store
     Set(SetCommand),
+    /// Get a value for a key from a store
+    Get(GetCommand),
+    /// Import a batch of key value pairs into a store from a JSON file
+    Import(ImportCommand),
+    /// Export all key value pairs in a store to a JSON file
+    Export(ExportCommand),
+    /// Remove one or more keys (or a key prefix) from a store
+    RemoveKey(RemoveKeyCommand),
+    /// Set or clear a quota on the number of keys and/or total bytes a store may hold
+    SetQuota(SetQuotaCommand),
     /// Rename a key value store. All existing links will automatically link to the store's new name.
     Rename(RenameCommand),
 }
@@ -81,6 +96,32 @@ impl From<GroupBy> for ResourceGroupBy {
     }
 }
 
+#[derive(Parser, Debug)]
+pub struct KeysCommand {
+    /// The name of the key value store
+    #[clap(name = "STORE", short = 's', long = "store", value_parser = clap::builder::ValueParser::new(disallow_empty), required_unless_present_all = ["LABEL", "APP"], conflicts_with_all = &["LABEL", "APP"])]
+    pub store: Option<String>,
+
+    /// Label of the key value store to list keys from
+    #[clap(name = "LABEL", short = 'l', long = "label", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "APP", required_unless_present = "STORE")]
+    pub label: Option<String>,
+
+    /// App to which label relates
+    #[clap(name = "APP", short = 'a', long = "app", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "LABEL", required_unless_present = "STORE")]
+    pub app: Option<String>,
+
+    /// Only list keys beginning with this prefix
+    #[clap(long = "prefix")]
+    pub prefix: Option<String>,
+
+    /// Format of list
+    #[clap(value_enum, long = "format", default_value = "table")]
+    format: ListFormat,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
 #[derive(Parser, Debug)]
 pub struct SetCommand {
     /// The name of the key value store
@@ -104,6 +145,141 @@ pub struct SetCommand {
     common: CommonArgs,
 }
 
+#[derive(Parser, Debug)]
+pub struct GetCommand {
+    /// The name of the key value store
+    #[clap(name = "STORE", short = 's', long = "store", value_parser = clap::builder::ValueParser::new(disallow_empty), required_unless_present_all = ["LABEL", "APP"], conflicts_with_all = &["LABEL", "APP"])]
+    pub store: Option<String>,
+
+    /// Label of the key value store to read from
+    #[clap(name = "LABEL", short = 'l', long = "label", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "APP", required_unless_present = "STORE")]
+    pub label: Option<String>,
+
+    /// App to which label relates
+    #[clap(name = "APP", short = 'a', long = "app", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "LABEL", required_unless_present = "STORE")]
+    pub app: Option<String>,
+
+    /// A key to read from the store. Can be used multiple times.
+    #[clap(name = "KEY", short = 'k', long = "key", required = true)]
+    pub keys: Vec<String>,
+
+    /// Format of output
+    #[clap(value_enum, long = "format", default_value = "table")]
+    format: ListFormat,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
+#[derive(Parser, Debug)]
+pub struct ImportCommand {
+    /// The name of the key value store
+    #[clap(name = "STORE", short = 's', long = "store", value_parser = clap::builder::ValueParser::new(disallow_empty), required_unless_present_all = ["LABEL", "APP"], conflicts_with_all = &["LABEL", "APP"])]
+    pub store: Option<String>,
+
+    /// Label of the key value store to import into
+    #[clap(name = "LABEL", short = 'l', long = "label", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "APP", required_unless_present = "STORE")]
+    pub label: Option<String>,
+
+    /// App to which label relates
+    #[clap(name = "APP", short = 'a', long = "app", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "LABEL", required_unless_present = "STORE")]
+    pub app: Option<String>,
+
+    /// JSON file containing key value pairs to import, in the format produced by `kv export`
+    #[clap(name = "FILE")]
+    pub file: PathBuf,
+
+    /// Delete all existing keys in the store before importing
+    #[clap(long = "replace", takes_value = false)]
+    pub replace: bool,
+
+    /// Skips prompt to confirm clearing existing keys when using '--replace'
+    #[clap(short = 'y', long = "yes", takes_value = false)]
+    yes: bool,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
+#[derive(Parser, Debug)]
+pub struct ExportCommand {
+    /// The name of the key value store
+    #[clap(name = "STORE", short = 's', long = "store", value_parser = clap::builder::ValueParser::new(disallow_empty), required_unless_present_all = ["LABEL", "APP"], conflicts_with_all = &["LABEL", "APP"])]
+    pub store: Option<String>,
+
+    /// Label of the key value store to export from
+    #[clap(name = "LABEL", short = 'l', long = "label", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "APP", required_unless_present = "STORE")]
+    pub label: Option<String>,
+
+    /// App to which label relates
+    #[clap(name = "APP", short = 'a', long = "app", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "LABEL", required_unless_present = "STORE")]
+    pub app: Option<String>,
+
+    /// File to write the exported key value pairs to. Prints to stdout if omitted.
+    #[clap(long = "out")]
+    pub out: Option<PathBuf>,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
+#[derive(Parser, Debug)]
+pub struct RemoveKeyCommand {
+    /// The name of the key value store
+    #[clap(name = "STORE", short = 's', long = "store", value_parser = clap::builder::ValueParser::new(disallow_empty), required_unless_present_all = ["LABEL", "APP"], conflicts_with_all = &["LABEL", "APP"])]
+    pub store: Option<String>,
+
+    /// Label of the key value store to remove keys from
+    #[clap(name = "LABEL", short = 'l', long = "label", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "APP", required_unless_present = "STORE")]
+    pub label: Option<String>,
+
+    /// App to which label relates
+    #[clap(name = "APP", short = 'a', long = "app", value_parser = clap::builder::ValueParser::new(disallow_empty), requires = "LABEL", required_unless_present = "STORE")]
+    pub app: Option<String>,
+
+    /// A key to remove from the store. Can be used multiple times.
+    #[clap(name = "KEY", short = 'k', long = "key")]
+    pub keys: Vec<String>,
+
+    /// Remove every key beginning with this prefix
+    #[clap(long = "prefix")]
+    pub prefix: Option<String>,
+
+    /// Skips prompt to confirm removal of the key(s)
+    #[clap(short = 'y', long = "yes", takes_value = false)]
+    yes: bool,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
+#[derive(Deserialize)]
+struct KeyValuePair {
+    key: String,
+    value: String,
+}
+
+#[derive(Parser, Debug)]
+pub struct SetQuotaCommand {
+    /// The name of the key value store
+    pub name: String,
+
+    /// Maximum number of keys allowed in the store
+    #[clap(long = "max-keys")]
+    pub max_keys: Option<u64>,
+
+    /// Maximum total serialized byte size of all values in the store
+    #[clap(long = "max-bytes")]
+    pub max_bytes: Option<u64>,
+
+    /// Remove any quota currently set on the store
+    #[clap(long = "clear-quota", takes_value = false, conflicts_with_all = &["max-keys", "max-bytes"])]
+    pub clear_quota: bool,
+
+    #[clap(flatten)]
+    common: CommonArgs,
+}
+
 #[derive(Parser, Debug)]
 pub struct RenameCommand {
     /// Current name of key value store to rename
@@ -131,10 +307,34 @@ impl KeyValueCommand {
                 let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
                 cmd.run(client).await
             }
+            KeyValueCommand::Keys(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
             KeyValueCommand::Set(cmd) => {
                 let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
                 cmd.run(client).await
             }
+            KeyValueCommand::Get(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
+            KeyValueCommand::Import(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
+            KeyValueCommand::Export(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
+            KeyValueCommand::RemoveKey(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
+            KeyValueCommand::SetQuota(cmd) => {
+                let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
+                cmd.run(client).await
+            }
             KeyValueCommand::Rename(cmd) => {
                 let client = create_cloud_client(cmd.common.deployment_env_id.as_deref()).await?;
                 cmd.run(client).await
@@ -196,23 +396,126 @@ impl ListCommand {
             println!("No key value stores found");
             return Ok(());
         }
-        let resource_links = key_value_stores
-            .into_iter()
-            .map(|kv| ResourceLinks::new(kv.name, kv.links))
+
+        let filtered_stores: Vec<&KeyValueStoreItem> = key_value_stores
+            .iter()
+            .filter(|kv| store_matches_app(kv, self.app.as_deref()))
             .collect();
+
+        match self.format {
+            ListFormat::Json => print_list_json(&client, &filtered_stores).await,
+            ListFormat::Table => {
+                let resource_links = key_value_stores
+                    .iter()
+                    .map(|kv| ResourceLinks::new(kv.name.clone(), kv.links.clone()))
+                    .collect();
+                print_table(
+                    resource_links,
+                    self.app.as_deref(),
+                    self.group_by.map(Into::into),
+                    ResourceType::KeyValueStore,
+                )?;
+                let names: Vec<String> = filtered_stores.iter().map(|kv| kv.name.clone()).collect();
+                print_quota_usage(&client, &names).await
+            }
+        }
+    }
+}
+
+fn store_matches_app(kv: &KeyValueStoreItem, app: Option<&str>) -> bool {
+    match app {
+        Some(app) => kv.links.iter().any(|link| link.app == app),
+        None => true,
+    }
+}
+
+async fn print_list_json(
+    client: &impl CloudClientInterface,
+    stores: &[&KeyValueStoreItem],
+) -> Result<()> {
+    let mut entries = Vec::with_capacity(stores.len());
+    for kv in stores {
+        let usage = client
+            .get_key_value_store_usage(kv.name.clone())
+            .await
+            .with_context(|| format!("Error fetching usage for key value store '{}'", kv.name))?;
+        entries.push(serde_json::json!({
+            "name": kv.name,
+            "links": kv.links,
+            "keyCount": usage.key_count,
+            "maxKeys": usage.max_keys,
+            "totalBytes": usage.total_bytes,
+            "maxBytes": usage.max_bytes,
+        }));
+    }
+    println!("{}", serde_json::to_string_pretty(&entries)?);
+    Ok(())
+}
+
+async fn print_quota_usage(client: &impl CloudClientInterface, stores: &[String]) -> Result<()> {
+    println!();
+    println!("{:<24}{:<16}{:<16}", "STORE", "KEYS", "BYTES");
+    for store in stores {
+        let usage = client
+            .get_key_value_store_usage(store.clone())
+            .await
+            .with_context(|| format!("Error fetching usage for key value store '{}'", store))?;
+        let keys = match usage.max_keys {
+            Some(max) => format!("{}/{max}", usage.key_count),
+            None => usage.key_count.to_string(),
+        };
+        let bytes = match usage.max_bytes {
+            Some(max) => format!("{}/{max}", usage.total_bytes),
+            None => usage.total_bytes.to_string(),
+        };
+        println!("{store:<24}{keys:<16}{bytes:<16}");
+    }
+    Ok(())
+}
+
+impl KeysCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        let target = ResourceTarget::from_inputs(&self.store, &self.label, &self.app)?;
+        let stores = client
+            .get_key_value_stores(None)
+            .await
+            .context("Problem fetching key value stores")?;
+        let store = target
+            .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
+            .name;
+
+        let mut keys = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next_cursor) = client
+                .list_key_value_keys(store.clone(), self.prefix.clone(), cursor.clone())
+                .await
+                .with_context(|| format!("Error listing keys in store '{}'", store))?;
+            keys.extend(page);
+            cursor = next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
         match self.format {
-            ListFormat::Json => print_json(
-                resource_links,
-                self.app.as_deref(),
-                ResourceType::KeyValueStore,
-            ),
-            ListFormat::Table => print_table(
-                resource_links,
-                self.app.as_deref(),
-                self.group_by.map(Into::into),
-                ResourceType::KeyValueStore,
-            ),
+            ListFormat::Json => {
+                let json = serde_json::to_string_pretty(&serde_json::json!({
+                    "store": store,
+                    "count": keys.len(),
+                    "keys": keys,
+                }))?;
+                println!("{json}");
+            }
+            ListFormat::Table => {
+                for key in &keys {
+                    println!("{key}");
+                }
+                println!("\n{} key(s) in store \"{}\"", keys.len(), store);
+            }
         }
+
+        Ok(())
     }
 }
 
@@ -226,6 +529,25 @@ impl SetCommand {
         let store = target
             .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
             .name;
+
+        let usage = client
+            .get_key_value_store_usage(store.clone())
+            .await
+            .with_context(|| format!("Error fetching usage for key value store '{}'", store))?;
+        if usage.max_keys.is_some() || usage.max_bytes.is_some() {
+            let mut existing = HashMap::with_capacity(self.key_values.len());
+            for (key, _) in &self.key_values {
+                if let Some(value) = client
+                    .get_key_value_pair(None, store.clone(), key.clone())
+                    .await
+                    .with_context(|| format!("Error reading key '{key}' from store '{}'", store))?
+                {
+                    existing.insert(key.clone(), value);
+                }
+            }
+            check_quota(&store, &usage, &existing, &self.key_values)?;
+        }
+
         for (key, value) in &self.key_values {
             client
                 .add_key_value_pair(None, store.clone(), key.clone(), value.clone())
@@ -241,6 +563,328 @@ impl SetCommand {
     }
 }
 
+impl GetCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        let target = ResourceTarget::from_inputs(&self.store, &self.label, &self.app)?;
+        let stores = client
+            .get_key_value_stores(None)
+            .await
+            .context("Problem fetching key value stores")?;
+        let store = target
+            .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
+            .name;
+
+        let mut pairs = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            let value = client
+                .get_key_value_pair(None, store.clone(), key.clone())
+                .await
+                .with_context(|| {
+                    format!("Error reading key '{key}' from store '{}'", store)
+                })?;
+            pairs.push((key.clone(), value));
+        }
+
+        match self.format {
+            ListFormat::Json => {
+                let json = serde_json::to_string_pretty(
+                    &pairs
+                        .iter()
+                        .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                        .collect::<Vec<_>>(),
+                )?;
+                println!("{json}");
+            }
+            ListFormat::Table => {
+                for (key, value) in &pairs {
+                    match value {
+                        Some(value) => println!("{key}\t{value}"),
+                        None => println!("{key}\t<not found>"),
+                    }
+                }
+            }
+        }
+
+        let missing: Vec<_> = pairs
+            .iter()
+            .filter(|(_, value)| value.is_none())
+            .map(|(key, _)| key.as_str())
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "Key(s) not found in store '{}': {}",
+                store,
+                missing.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl ImportCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        let target = ResourceTarget::from_inputs(&self.store, &self.label, &self.app)?;
+        let stores = client
+            .get_key_value_stores(None)
+            .await
+            .context("Problem fetching key value stores")?;
+        let store = target
+            .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
+            .name;
+
+        let contents = std::fs::read_to_string(&self.file)
+            .with_context(|| format!("Error reading import file '{}'", self.file.display()))?;
+        let pairs: Vec<KeyValuePair> = serde_json::from_str(&contents)
+            .with_context(|| format!("Error parsing import file '{}'", self.file.display()))?;
+
+        let mut existing: HashMap<String, String> = client
+            .get_key_value_pairs(store.clone())
+            .await
+            .with_context(|| {
+                format!("Error reading existing key value pairs from store '{}'", store)
+            })?
+            .into_iter()
+            .collect();
+
+        if self.replace && !existing.is_empty() {
+            if !self.yes && !prompt_clear_keys(&store, existing.len())? {
+                return Ok(());
+            }
+            for key in existing.keys().cloned().collect::<Vec<_>>() {
+                client
+                    .delete_key_value_pair(None, store.clone(), key.clone())
+                    .await
+                    .with_context(|| format!("Error removing key '{key}' from store '{}'", store))?;
+            }
+            existing.clear();
+        }
+
+        let usage = client
+            .get_key_value_store_usage(store.clone())
+            .await
+            .with_context(|| format!("Error fetching usage for key value store '{}'", store))?;
+        let incoming: Vec<(String, String)> = pairs
+            .iter()
+            .map(|p| (p.key.clone(), p.value.clone()))
+            .collect();
+        check_quota(&store, &usage, &existing, &incoming)?;
+
+        let count = pairs.len();
+        client
+            .add_key_value_pairs(
+                None,
+                store.clone(),
+                pairs.into_iter().map(|p| (p.key, p.value)).collect(),
+            )
+            .await
+            .with_context(|| format!("Error importing key value pairs into store '{}'", store))?;
+
+        println!("Imported {count} key value pair(s) into store \"{}\"", store);
+        Ok(())
+    }
+}
+
+impl ExportCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        let target = ResourceTarget::from_inputs(&self.store, &self.label, &self.app)?;
+        let stores = client
+            .get_key_value_stores(None)
+            .await
+            .context("Problem fetching key value stores")?;
+        let store = target
+            .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
+            .name;
+
+        let pairs = client
+            .get_key_value_pairs(store.clone())
+            .await
+            .with_context(|| format!("Error exporting key value pairs from store '{}'", store))?;
+
+        let json = serde_json::to_string_pretty(
+            &pairs
+                .into_iter()
+                .map(|(key, value)| serde_json::json!({ "key": key, "value": value }))
+                .collect::<Vec<_>>(),
+        )?;
+
+        match &self.out {
+            Some(path) => {
+                std::fs::write(path, json)
+                    .with_context(|| format!("Error writing export file '{}'", path.display()))?;
+                println!("Exported key value pairs from store \"{}\" to {}", store, path.display());
+            }
+            None => println!("{json}"),
+        }
+
+        Ok(())
+    }
+}
+
+impl RemoveKeyCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        if self.keys.is_empty() && self.prefix.is_none() {
+            bail!("Must specify at least one '--key' or a '--prefix' to remove");
+        }
+
+        let target = ResourceTarget::from_inputs(&self.store, &self.label, &self.app)?;
+        let stores = client
+            .get_key_value_stores(None)
+            .await
+            .context("Problem fetching key value stores")?;
+        let store = target
+            .find_in(to_resource_links(stores), ResourceType::KeyValueStore)?
+            .name;
+
+        if !self.yes && !prompt_remove_keys(&store, &self.keys, self.prefix.as_deref())? {
+            return Ok(());
+        }
+
+        // `delete_key_value_pair` is assumed idempotent (a missing key is not an error), so
+        // `removed` reflects successful calls rather than keys that were provably present.
+        let mut seen = std::collections::HashSet::with_capacity(self.keys.len());
+        let keys: Vec<&String> = self.keys.iter().filter(|key| seen.insert(key.as_str())).collect();
+
+        let mut removed = 0usize;
+        let mut failures = Vec::new();
+        for key in keys {
+            match client
+                .delete_key_value_pair(None, store.clone(), key.clone())
+                .await
+            {
+                Ok(()) => removed += 1,
+                Err(err) => failures.push(format!("key '{key}': {err}")),
+            }
+        }
+
+        if let Some(prefix) = &self.prefix {
+            match client
+                .delete_key_value_pairs_by_prefix(None, store.clone(), prefix.clone())
+                .await
+            {
+                Ok(count) => removed += count,
+                Err(err) => failures.push(format!("prefix '{prefix}': {err}")),
+            }
+        }
+
+        println!("Removed {removed} key(s) from store \"{}\"", store);
+
+        if !failures.is_empty() {
+            bail!(
+                "Failed to remove {} of the requested key(s)/prefix(es) from store '{}':\n{}",
+                failures.len(),
+                store,
+                failures.join("\n")
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn prompt_remove_keys(store: &str, keys: &[String], prefix: Option<&str>) -> Result<bool> {
+    let prompt = match (keys, prefix) {
+        (keys, Some(prefix)) if !keys.is_empty() => format!(
+            "Remove {} key(s) and all keys prefixed with \"{prefix}\" from store \"{store}\"?",
+            keys.len()
+        ),
+        (_, Some(prefix)) => format!("Remove all keys prefixed with \"{prefix}\" from store \"{store}\"?"),
+        (keys, None) => format!("Remove {} key(s) from store \"{store}\"?", keys.len()),
+    };
+    Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()
+        .context("Error prompting for removal confirmation")
+}
+
+fn prompt_clear_keys(store: &str, count: usize) -> Result<bool> {
+    Confirm::new()
+        .with_prompt(format!(
+            "Remove all {count} existing key(s) from store \"{store}\" before importing?"
+        ))
+        .default(false)
+        .interact()
+        .context("Error prompting for store clear confirmation")
+}
+
+impl SetQuotaCommand {
+    pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
+        if !self.clear_quota && self.max_keys.is_none() && self.max_bytes.is_none() {
+            bail!("Must specify '--max-keys' and/or '--max-bytes', or '--clear-quota'");
+        }
+
+        let list = client
+            .get_key_value_stores(None)
+            .await
+            .with_context(|| format!("Error listing key value stores '{}'", self.name))?;
+        if !list.iter().any(|kv| kv.name == self.name) {
+            bail!("No key value store found with name \"{}\"", self.name);
+        }
+
+        let (max_keys, max_bytes) = if self.clear_quota {
+            (None, None)
+        } else {
+            (self.max_keys, self.max_bytes)
+        };
+
+        client
+            .set_key_value_store_quota(&self.name, max_keys, max_bytes)
+            .await
+            .with_context(|| format!("Error setting quota for key value store '{}'", self.name))?;
+
+        if self.clear_quota {
+            println!("Quota cleared for key value store \"{}\"", self.name);
+        } else {
+            println!("Quota set for key value store \"{}\"", self.name);
+        }
+        Ok(())
+    }
+}
+
+fn check_quota(
+    store: &str,
+    usage: &KeyValueStoreUsage,
+    existing: &HashMap<String, String>,
+    incoming: &[(String, String)],
+) -> Result<()> {
+    // A key repeated within the same batch only results in one write, keeping its last value.
+    let mut deduped: HashMap<&str, &str> = HashMap::with_capacity(incoming.len());
+    for (key, value) in incoming {
+        deduped.insert(key.as_str(), value.as_str());
+    }
+
+    let mut new_keys: i64 = 0;
+    let mut delta_bytes: i64 = 0;
+    for (key, value) in &deduped {
+        match existing.get(*key) {
+            Some(old_value) => delta_bytes += value.len() as i64 - old_value.len() as i64,
+            None => {
+                new_keys += 1;
+                delta_bytes += value.len() as i64;
+            }
+        }
+    }
+
+    if let Some(max_keys) = usage.max_keys {
+        let projected_keys = usage.key_count as i64 + new_keys;
+        if projected_keys > max_keys as i64 {
+            bail!(
+                "Quota exceeded for key value store '{store}': adding {new_keys} new key(s) would bring the store to {projected_keys} keys, exceeding the max of {max_keys}"
+            );
+        }
+    }
+    if let Some(max_bytes) = usage.max_bytes {
+        let projected_bytes = usage.total_bytes as i64 + delta_bytes;
+        if projected_bytes > max_bytes as i64 {
+            bail!(
+                "Quota exceeded for key value store '{store}': this change would bring the store to {projected_bytes} bytes, exceeding the max of {max_bytes}"
+            );
+        }
+    }
+    Ok(())
+}
+
 impl RenameCommand {
     pub async fn run(&self, client: impl CloudClientInterface) -> Result<()> {
         let list = client
@@ -351,4 +995,328 @@ mod key_value_tests {
 
         command.run(mock).await
     }
+
+    fn no_quota_usage() -> KeyValueStoreUsage {
+        KeyValueStoreUsage {
+            key_count: 0,
+            total_bytes: 0,
+            max_keys: None,
+            max_bytes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_reports_missing_keys() -> Result<()> {
+        let command = GetCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            keys: vec!["present".to_string(), "absent".to_string()],
+            format: ListFormat::Table,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_pair()
+            .withf(|_, store, key| store == "kv1" && key == "present")
+            .returning(|_, _, _| Ok(Some("value".to_string())));
+        mock.expect_get_key_value_pair()
+            .withf(|_, store, key| store == "kv1" && key == "absent")
+            .returning(|_, _, _| Ok(None));
+
+        let result = command.run(mock).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            r#"Key(s) not found in store 'kv1': absent"#
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_all_keys_found_succeeds() -> Result<()> {
+        let command = GetCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            keys: vec!["present".to_string()],
+            format: ListFormat::Table,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_pair()
+            .returning(|_, _, _| Ok(Some("value".to_string())));
+
+        command.run(mock).await
+    }
+
+    #[tokio::test]
+    async fn test_set_rejects_write_that_exceeds_key_quota() -> Result<()> {
+        let command = SetCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            key_values: vec![("new".to_string(), "value".to_string())],
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_store_usage().returning(|_| {
+            Ok(KeyValueStoreUsage {
+                key_count: 1,
+                total_bytes: 5,
+                max_keys: Some(1),
+                max_bytes: None,
+            })
+        });
+        mock.expect_get_key_value_pair()
+            .withf(|_, store, key| store == "kv1" && key == "new")
+            .returning(|_, _, _| Ok(None));
+
+        let result = command.run(mock).await;
+        assert!(result.unwrap_err().to_string().contains("Quota exceeded"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_allows_overwrite_of_existing_key_at_quota() -> Result<()> {
+        let command = SetCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            key_values: vec![("existing".to_string(), "new-value".to_string())],
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_store_usage().returning(|_| {
+            Ok(KeyValueStoreUsage {
+                key_count: 1,
+                total_bytes: 5,
+                max_keys: Some(1),
+                max_bytes: None,
+            })
+        });
+        mock.expect_get_key_value_pair()
+            .withf(|_, store, key| store == "kv1" && key == "existing")
+            .returning(|_, _, _| Ok(Some("value".to_string())));
+        mock.expect_add_key_value_pair()
+            .withf(|_, store, key, value| store == "kv1" && key == "existing" && value == "new-value")
+            .returning(|_, _, _, _| Ok(()));
+
+        command.run(mock).await
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_write_that_exceeds_key_quota() -> Result<()> {
+        let dir = std::env::temp_dir();
+        let file = dir.join(format!(
+            "spin_cloud_kv_import_quota_test_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&file, r#"[{"key":"a","value":"1"},{"key":"b","value":"2"}]"#)?;
+
+        let command = ImportCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            file: file.clone(),
+            replace: false,
+            yes: true,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_pairs().returning(|_| Ok(vec![]));
+        mock.expect_get_key_value_store_usage().returning(|_| {
+            Ok(KeyValueStoreUsage {
+                key_count: 0,
+                total_bytes: 0,
+                max_keys: Some(1),
+                max_bytes: None,
+            })
+        });
+
+        let result = command.run(mock).await;
+        std::fs::remove_file(&file).ok();
+        assert!(result.unwrap_err().to_string().contains("Quota exceeded"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_import_replace_clears_existing_keys_before_importing() -> Result<()> {
+        let dir = std::env::temp_dir();
+        let file = dir.join(format!(
+            "spin_cloud_kv_import_test_{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&file, r#"[{"key":"a","value":"1"}]"#)?;
+
+        let command = ImportCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            file: file.clone(),
+            replace: true,
+            yes: true,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_pairs()
+            .returning(|_| Ok(vec![("old".to_string(), "stale".to_string())]));
+        mock.expect_delete_key_value_pair()
+            .withf(|_, store, key| store == "kv1" && key == "old")
+            .returning(|_, _, _| Ok(()));
+        mock.expect_get_key_value_store_usage()
+            .returning(|_| Ok(no_quota_usage()));
+        mock.expect_add_key_value_pairs()
+            .withf(|_, store, pairs| {
+                store == "kv1" && pairs == &vec![("a".to_string(), "1".to_string())]
+            })
+            .returning(|_, _, _| Ok(()));
+
+        let result = command.run(mock).await;
+        std::fs::remove_file(&file).ok();
+        result
+    }
+
+    #[tokio::test]
+    async fn test_export_writes_pairs_to_file() -> Result<()> {
+        let dir = std::env::temp_dir();
+        let file = dir.join(format!(
+            "spin_cloud_kv_export_test_{}.json",
+            std::process::id()
+        ));
+
+        let command = ExportCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            out: Some(file.clone()),
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_get_key_value_pairs()
+            .returning(|_| Ok(vec![("a".to_string(), "1".to_string())]));
+
+        command.run(mock).await?;
+        let contents = std::fs::read_to_string(&file)?;
+        std::fs::remove_file(&file).ok();
+        assert!(contents.contains(r#""key": "a""#));
+        assert!(contents.contains(r#""value": "1""#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_key_reports_partial_failure_without_aborting() -> Result<()> {
+        let command = RemoveKeyCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            keys: vec!["good".to_string(), "bad".to_string()],
+            prefix: None,
+            yes: true,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_delete_key_value_pair()
+            .withf(|_, _, key| key == "good")
+            .returning(|_, _, _| Ok(()));
+        mock.expect_delete_key_value_pair()
+            .withf(|_, _, key| key == "bad")
+            .returning(|_, _, _| Err(anyhow::anyhow!("not found")));
+
+        let result = command.run(mock).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to remove 1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_keys_paginates_until_cursor_exhausted() -> Result<()> {
+        let command = KeysCommand {
+            store: Some("kv1".to_string()),
+            label: None,
+            app: None,
+            prefix: None,
+            format: ListFormat::Table,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_list_key_value_keys()
+            .withf(|_, _, cursor| cursor.is_none())
+            .returning(|_, _, _| Ok((vec!["a".to_string()], Some("next".to_string()))));
+        mock.expect_list_key_value_keys()
+            .withf(|_, _, cursor| cursor.as_deref() == Some("next"))
+            .returning(|_, _, _| Ok((vec!["b".to_string()], None)));
+
+        command.run(mock).await
+    }
+
+    #[tokio::test]
+    async fn test_set_quota_errors_if_store_missing() -> Result<()> {
+        let command = SetQuotaCommand {
+            name: "kv1".to_string(),
+            max_keys: Some(10),
+            max_bytes: None,
+            clear_quota: false,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores().returning(|_| Ok(vec![]));
+
+        let result = command.run(mock).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            r#"No key value store found with name "kv1""#
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_quota_clear_passes_none_for_both_limits() -> Result<()> {
+        let command = SetQuotaCommand {
+            name: "kv1".to_string(),
+            max_keys: None,
+            max_bytes: None,
+            clear_quota: true,
+            common: Default::default(),
+        };
+
+        let mut mock = MockCloudClientInterface::new();
+        mock.expect_get_key_value_stores()
+            .returning(move |_| Ok(vec![KeyValueStoreItem::new("kv1".to_string(), vec![])]));
+        mock.expect_set_key_value_store_quota()
+            .withf(|name, max_keys, max_bytes| {
+                name == "kv1" && max_keys.is_none() && max_bytes.is_none()
+            })
+            .returning(|_, _, _| Ok(()));
+
+        command.run(mock).await
+    }
 }